@@ -0,0 +1,44 @@
+/// Tiny indentation-aware string builder used by the `gen` emitters.
+///
+/// The real code generator's `CodeWriter` also handles block scoping
+/// (`write_line`/`indented`/etc over a `fmt::Write`); this carries only what
+/// the emitters in this crate need.
+#[derive(Default)]
+pub(crate) struct CodeWriter {
+    indent: usize,
+    lines: Vec<String>,
+}
+
+impl CodeWriter {
+    pub(crate) fn new() -> CodeWriter {
+        CodeWriter::default()
+    }
+
+    /// A writer starting at `indent` levels deep, for emitting into a scope
+    /// (e.g. an `impl` block) that's already open.
+    pub(crate) fn new_indented(indent: usize) -> CodeWriter {
+        CodeWriter {
+            indent,
+            ..CodeWriter::default()
+        }
+    }
+
+    pub(crate) fn write_line(&mut self, line: impl AsRef<str>) {
+        let line = line.as_ref();
+        if line.is_empty() {
+            self.lines.push(String::new());
+        } else {
+            self.lines.push(format!("{}{}", "    ".repeat(self.indent), line));
+        }
+    }
+
+    pub(crate) fn indented(&mut self, cb: impl FnOnce(&mut CodeWriter)) {
+        self.indent += 1;
+        cb(self);
+        self.indent -= 1;
+    }
+
+    pub(crate) fn into_string(self) -> String {
+        self.lines.join("\n")
+    }
+}
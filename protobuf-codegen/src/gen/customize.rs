@@ -0,0 +1,36 @@
+/// Per-invocation codegen options, set from `.proto` file/message options or
+/// from the `Codegen` builder.
+///
+/// Only the options exercised by the chunk0-* backlog requests are modeled
+/// here; the real `Customize` also carries `lite_runtime`, `inside_protobuf`,
+/// `gen_mod_rs`, and friends.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Customize {
+    /// Emit `#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]`
+    /// on generated message structs and `#[cfg_attr(feature = "with-serde", serde(skip))]`
+    /// on `special_fields`/`unknown_fields`.
+    pub with_serde: bool,
+    /// Represent singular `string` fields as `crate::chars::Chars` (and parse
+    /// them zero-copy via `CodedInputStream::read_chars`) instead of
+    /// `::std::string::String`.
+    ///
+    /// The flag is only stored: the emitters in this crate do not read it, and
+    /// `SingularStringField` takes its `StringFieldRepresentation` from the caller.
+    pub bytes_for_string: bool,
+}
+
+impl Customize {
+    pub fn new() -> Customize {
+        Customize::default()
+    }
+
+    pub fn with_serde(mut self, with_serde: bool) -> Customize {
+        self.with_serde = with_serde;
+        self
+    }
+
+    pub fn bytes_for_string(mut self, bytes_for_string: bool) -> Customize {
+        self.bytes_for_string = bytes_for_string;
+        self
+    }
+}
@@ -0,0 +1,74 @@
+use crate::gen::code_writer::CodeWriter;
+use crate::gen::customize::Customize;
+
+/// Write the `#[derive(...)]` line(s) that sit above every generated message
+/// struct, for example:
+///
+/// ```ignore
+/// #[derive(PartialEq,Clone,Default,Debug)]
+/// #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+/// pub struct SourceContext { ... }
+/// ```
+///
+/// The output does not depend on the message, so the same lines serve every
+/// generated message struct; `crate::message_struct_attrs` renders them.
+pub(crate) fn write_message_struct_attrs(w: &mut CodeWriter, customize: &Customize) {
+    w.write_line("#[derive(PartialEq,Clone,Default,Debug)]");
+    if customize.with_serde {
+        w.write_line(
+            r#"#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]"#,
+        );
+    }
+}
+
+/// Write the `special_fields` field declaration, skipping it from serde
+/// (un)serialization since it carries unknown fields and cached sizes, not
+/// message data.
+pub(crate) fn write_special_fields_field(w: &mut CodeWriter, customize: &Customize) {
+    if customize.with_serde {
+        w.write_line(r#"#[cfg_attr(feature = "with-serde", serde(skip))]"#);
+    }
+    w.write_line("pub special_fields: crate::SpecialFields,");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE_CONTEXT_SRC: &str =
+        include_str!("../../../protobuf/src/well_known_types/source_context.rs");
+
+    /// `SourceContext`'s hand-kept struct attrs must be exactly what this
+    /// template emits for `Customize::with_serde(true)`, byte for byte --
+    /// this is the drift check the template exists to make possible.
+    #[test]
+    fn struct_attrs_match_source_context() {
+        let mut w = CodeWriter::new();
+        write_message_struct_attrs(&mut w, &Customize::new().with_serde(true));
+        assert!(SOURCE_CONTEXT_SRC.contains(&w.into_string()));
+    }
+
+    #[test]
+    fn special_fields_field_matches_source_context() {
+        let mut w = CodeWriter::new_indented(1);
+        write_special_fields_field(&mut w, &Customize::new().with_serde(true));
+        assert!(SOURCE_CONTEXT_SRC.contains(&w.into_string()));
+    }
+
+    #[test]
+    fn with_serde_emits_cfg_attr() {
+        let mut w = CodeWriter::new();
+        write_message_struct_attrs(&mut w, &Customize::new().with_serde(true));
+        let out = w.into_string();
+        assert!(out.contains(r#"cfg_attr(feature = "with-serde""#));
+        assert!(out.contains("::serde::Serialize"));
+    }
+
+    #[test]
+    fn without_serde_emits_nothing_extra() {
+        let mut w = CodeWriter::new();
+        write_message_struct_attrs(&mut w, &Customize::new());
+        let out = w.into_string();
+        assert!(!out.contains("serde"));
+    }
+}
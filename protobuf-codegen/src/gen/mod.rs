@@ -0,0 +1,4 @@
+pub(crate) mod code_writer;
+pub(crate) mod customize;
+pub(crate) mod message;
+pub(crate) mod singular_field;
@@ -0,0 +1,143 @@
+use crate::gen::code_writer::CodeWriter;
+
+/// How a singular `string` field is represented in the generated struct, and
+/// therefore how `merge_from`/accessors read and expose it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringFieldRepresentation {
+    /// `::std::string::String`, read via `CodedInputStream::read_string`.
+    String,
+    /// `crate::chars::Chars`, read zero-copy via `CodedInputStream::read_chars`
+    /// (see `Customize::bytes_for_string`).
+    Chars,
+}
+
+impl StringFieldRepresentation {
+    fn rust_type(self) -> &'static str {
+        match self {
+            StringFieldRepresentation::String => "::std::string::String",
+            StringFieldRepresentation::Chars => "crate::chars::Chars",
+        }
+    }
+
+    /// The expression `merge_from` uses to decode this field's wire bytes.
+    pub fn read_expr(self) -> &'static str {
+        match self {
+            StringFieldRepresentation::String => "is.read_string()?",
+            StringFieldRepresentation::Chars => "is.read_chars()?",
+        }
+    }
+}
+
+/// Emits the getter/setter/mut/clear/take accessor set for one singular
+/// `string` field, reinstating what public-field-only generated structs lost.
+///
+/// This is generic over the field's Rust name and representation, so one
+/// value serves any `string` field of any message. The `SourceContext.file_name`
+/// accessors in this checkout are a hand-kept copy of its output, not produced
+/// by calling it. `test::file_name_matches_source_context` pins the two together:
+/// it fails if either one drifts from the other.
+pub struct SingularStringField {
+    pub rust_name: String,
+    pub representation: StringFieldRepresentation,
+}
+
+impl SingularStringField {
+    pub fn new(rust_name: impl Into<String>, representation: StringFieldRepresentation) -> SingularStringField {
+        SingularStringField {
+            rust_name: rust_name.into(),
+            representation,
+        }
+    }
+
+    /// Write this field's accessor methods at the writer's current indent,
+    /// as they'd appear directly inside the message's `impl` block.
+    pub(crate) fn write_accessors(&self, w: &mut CodeWriter) {
+        let name = &self.rust_name;
+        let ty = self.representation.rust_type();
+
+        w.write_line(format!("// {}", name));
+        w.write_line("");
+
+        w.write_line(format!("pub fn {}(&self) -> &str {{", name));
+        w.indented(|w| w.write_line(format!("&self.{}", name)));
+        w.write_line("}");
+        w.write_line("");
+
+        w.write_line(format!("pub fn clear_{}(&mut self) {{", name));
+        w.indented(|w| w.write_line(format!("self.{}.clear();", name)));
+        w.write_line("}");
+        w.write_line("");
+
+        w.write_line(format!("pub fn set_{}(&mut self, v: {}) {{", name, ty));
+        w.indented(|w| w.write_line(format!("self.{} = v;", name)));
+        w.write_line("}");
+        w.write_line("");
+
+        w.write_line("// Mutable pointer to the field.");
+        w.write_line(format!("pub fn mut_{}(&mut self) -> &mut {} {{", name, ty));
+        w.indented(|w| w.write_line(format!("&mut self.{}", name)));
+        w.write_line("}");
+        w.write_line("");
+
+        w.write_line("// Take field");
+        w.write_line(format!("pub fn take_{}(&mut self) -> {} {{", name, ty));
+        w.indented(|w| {
+            w.write_line(format!(
+                "::std::mem::replace(&mut self.{}, {}::new())",
+                name, ty
+            ))
+        });
+        w.write_line("}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE_CONTEXT_SRC: &str =
+        include_str!("../../../protobuf/src/well_known_types/source_context.rs");
+
+    #[test]
+    fn string_field_emits_expected_methods() {
+        let field = SingularStringField::new("file_name", StringFieldRepresentation::String);
+        let mut w = CodeWriter::new();
+        field.write_accessors(&mut w);
+        let out = w.into_string();
+
+        assert!(out.contains("pub fn file_name(&self) -> &str {"));
+        assert!(out.contains("pub fn set_file_name(&mut self, v: ::std::string::String) {"));
+        assert!(out.contains("pub fn mut_file_name(&mut self) -> &mut ::std::string::String {"));
+        assert!(out.contains("pub fn take_file_name(&mut self) -> ::std::string::String {"));
+        assert!(out.contains("pub fn clear_file_name(&mut self) {"));
+        assert_eq!("is.read_string()?", field.representation.read_expr());
+    }
+
+    #[test]
+    fn chars_field_is_zero_copy() {
+        let field = SingularStringField::new("file_name", StringFieldRepresentation::Chars);
+        assert_eq!("is.read_chars()?", field.representation.read_expr());
+
+        let mut w = CodeWriter::new();
+        field.write_accessors(&mut w);
+        assert!(w.into_string().contains("crate::chars::Chars"));
+    }
+
+    /// `SourceContext.file_name`'s hand-kept accessors must be exactly what
+    /// this template would emit for a `String`-represented `file_name`
+    /// field, byte for byte -- this is the drift check the template exists
+    /// to make possible.
+    #[test]
+    fn file_name_matches_source_context() {
+        let field = SingularStringField::new("file_name", StringFieldRepresentation::String);
+        let mut w = CodeWriter::new_indented(1);
+        field.write_accessors(&mut w);
+        let generated = w.into_string();
+
+        assert!(
+            SOURCE_CONTEXT_SRC.contains(&generated),
+            "generated accessors:\n{}\n\nnot found verbatim in source_context.rs",
+            generated
+        );
+    }
+}
@@ -73,6 +73,22 @@ impl From<String> for Chars {
     }
 }
 
+impl ::std::convert::TryFrom<Bytes> for Chars {
+    type Error = str::Utf8Error;
+
+    /// Zero-copy conversion: the resulting `Chars` borrows from the same
+    /// underlying buffer as `bytes`, no string data is copied.
+    fn try_from(bytes: Bytes) -> Result<Chars, str::Utf8Error> {
+        Chars::from_bytes(bytes)
+    }
+}
+
+impl From<Chars> for Bytes {
+    fn from(chars: Chars) -> Bytes {
+        chars.0
+    }
+}
+
 impl Into<String> for Chars {
     fn into(self) -> String {
         // This is safe because `Chars` is guaranteed to store a valid UTF-8 string
@@ -115,9 +131,32 @@ impl fmt::Debug for Chars {
     }
 }
 
+#[cfg(feature = "with-serde")]
+impl ::serde::Serialize for Chars {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de> ::serde::Deserialize<'de> for Chars {
+    fn deserialize<D>(deserializer: D) -> Result<Chars, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Chars::from(s))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Chars;
+    use bytes::Bytes;
+    use std::convert::TryFrom;
 
     #[test]
     #[cfg_attr(miri, ignore)] // bytes violates SB, see https://github.com/tokio-rs/bytes/issues/522
@@ -129,4 +168,20 @@ mod test {
         assert_eq!(format!("{}", string), format!("{}", chars));
         assert_eq!(format!("{:?}", string), format!("{:?}", chars));
     }
+
+    #[test]
+    fn test_try_from_bytes_is_zero_copy() {
+        let bytes = Bytes::from_static(b"hello");
+        let ptr = bytes.as_ptr();
+        let chars = Chars::try_from(bytes).unwrap();
+
+        assert_eq!("hello", &*chars);
+        assert_eq!(ptr, Bytes::from(chars).as_ptr());
+    }
+
+    #[test]
+    fn test_try_from_bytes_invalid_utf8() {
+        let bytes = Bytes::from_static(&[0xff, 0xfe]);
+        assert!(Chars::try_from(bytes).is_err());
+    }
 }
@@ -4,6 +4,7 @@ use crate::reflect::value::hashable::ReflectValueBoxHashable;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
+use std::collections::hash_map;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -62,6 +63,54 @@ impl Maps {
     }
 }
 
+/// Iterator over one of the six concrete `Maps` variants, yielding reflection values.
+enum DynamicMapIter<'a> {
+    U32(hash_map::Iter<'a, u32, ReflectValueBox>),
+    I32(hash_map::Iter<'a, i32, ReflectValueBox>),
+    U64(hash_map::Iter<'a, u64, ReflectValueBox>),
+    I64(hash_map::Iter<'a, i64, ReflectValueBox>),
+    Bool(hash_map::Iter<'a, bool, ReflectValueBox>),
+    String(hash_map::Iter<'a, String, ReflectValueBox>),
+}
+
+impl<'a> Iterator for DynamicMapIter<'a> {
+    type Item = (ReflectValueRef<'a>, ReflectValueRef<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            DynamicMapIter::U32(it) => it
+                .next()
+                .map(|(k, v)| (ReflectValueRef::U32(*k), v.as_value_ref())),
+            DynamicMapIter::I32(it) => it
+                .next()
+                .map(|(k, v)| (ReflectValueRef::I32(*k), v.as_value_ref())),
+            DynamicMapIter::U64(it) => it
+                .next()
+                .map(|(k, v)| (ReflectValueRef::U64(*k), v.as_value_ref())),
+            DynamicMapIter::I64(it) => it
+                .next()
+                .map(|(k, v)| (ReflectValueRef::I64(*k), v.as_value_ref())),
+            DynamicMapIter::Bool(it) => it
+                .next()
+                .map(|(k, v)| (ReflectValueRef::Bool(*k), v.as_value_ref())),
+            DynamicMapIter::String(it) => it
+                .next()
+                .map(|(k, v)| (ReflectValueRef::String(k), v.as_value_ref())),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            DynamicMapIter::U32(it) => it.size_hint(),
+            DynamicMapIter::I32(it) => it.size_hint(),
+            DynamicMapIter::U64(it) => it.size_hint(),
+            DynamicMapIter::I64(it) => it.size_hint(),
+            DynamicMapIter::Bool(it) => it.size_hint(),
+            DynamicMapIter::String(it) => it.size_hint(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct DynamicMap {
     value: RuntimeTypeBox,
@@ -87,8 +136,15 @@ impl DynamicMap {
 
 impl ReflectMap for DynamicMap {
     fn reflect_iter(&self) -> ReflectMapIter {
-        // TODO
-        unimplemented!()
+        let iter = match &self.maps {
+            Maps::U32(m) => DynamicMapIter::U32(m.iter()),
+            Maps::I32(m) => DynamicMapIter::I32(m.iter()),
+            Maps::U64(m) => DynamicMapIter::U64(m.iter()),
+            Maps::I64(m) => DynamicMapIter::I64(m.iter()),
+            Maps::Bool(m) => DynamicMapIter::Bool(m.iter()),
+            Maps::String(m) => DynamicMapIter::String(m.iter()),
+        };
+        ReflectMapIter::new(iter)
     }
 
     fn len(&self) -> usize {
@@ -139,4 +195,80 @@ impl ReflectMap for DynamicMap {
     fn value_type(&self) -> RuntimeTypeBox {
         self.value.clone()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::DynamicMap;
+    use crate::reflect::map::ReflectMap;
+    use crate::reflect::value::hashable::ReflectValueBoxHashable;
+    use crate::reflect::ReflectValueBox;
+    use crate::reflect::RuntimeTypeBox;
+    use std::collections::HashSet;
+
+    fn collect_keys(map: &DynamicMap) -> HashSet<String> {
+        map.reflect_iter()
+            .map(|(k, _)| format!("{:?}", k))
+            .collect()
+    }
+
+    #[test]
+    fn reflect_iter_u32() {
+        let mut map = DynamicMap::new(RuntimeTypeBox::U32, RuntimeTypeBox::I32);
+        map.insert(ReflectValueBoxHashable::U32(1), ReflectValueBox::I32(10));
+        map.insert(ReflectValueBoxHashable::U32(2), ReflectValueBox::I32(20));
+        assert_eq!(2, map.reflect_iter().count());
+        assert_eq!(collect_keys(&map).len(), 2);
+    }
+
+    #[test]
+    fn reflect_iter_i32() {
+        let mut map = DynamicMap::new(RuntimeTypeBox::I32, RuntimeTypeBox::I32);
+        map.insert(ReflectValueBoxHashable::I32(-1), ReflectValueBox::I32(10));
+        map.insert(ReflectValueBoxHashable::I32(-2), ReflectValueBox::I32(20));
+        assert_eq!(2, map.reflect_iter().count());
+        assert_eq!(collect_keys(&map).len(), 2);
+    }
+
+    #[test]
+    fn reflect_iter_u64() {
+        let mut map = DynamicMap::new(RuntimeTypeBox::U64, RuntimeTypeBox::I32);
+        map.insert(ReflectValueBoxHashable::U64(1), ReflectValueBox::I32(10));
+        map.insert(ReflectValueBoxHashable::U64(2), ReflectValueBox::I32(20));
+        assert_eq!(2, map.reflect_iter().count());
+        assert_eq!(collect_keys(&map).len(), 2);
+    }
+
+    #[test]
+    fn reflect_iter_i64() {
+        let mut map = DynamicMap::new(RuntimeTypeBox::I64, RuntimeTypeBox::I32);
+        map.insert(ReflectValueBoxHashable::I64(-1), ReflectValueBox::I32(10));
+        map.insert(ReflectValueBoxHashable::I64(-2), ReflectValueBox::I32(20));
+        assert_eq!(2, map.reflect_iter().count());
+        assert_eq!(collect_keys(&map).len(), 2);
+    }
+
+    #[test]
+    fn reflect_iter_bool() {
+        let mut map = DynamicMap::new(RuntimeTypeBox::Bool, RuntimeTypeBox::I32);
+        map.insert(ReflectValueBoxHashable::Bool(true), ReflectValueBox::I32(10));
+        map.insert(ReflectValueBoxHashable::Bool(false), ReflectValueBox::I32(20));
+        assert_eq!(2, map.reflect_iter().count());
+        assert_eq!(collect_keys(&map).len(), 2);
+    }
+
+    #[test]
+    fn reflect_iter_string() {
+        let mut map = DynamicMap::new(RuntimeTypeBox::String, RuntimeTypeBox::I32);
+        map.insert(
+            ReflectValueBoxHashable::String("a".to_string()),
+            ReflectValueBox::I32(10),
+        );
+        map.insert(
+            ReflectValueBoxHashable::String("b".to_string()),
+            ReflectValueBox::I32(20),
+        );
+        assert_eq!(2, map.reflect_iter().count());
+        assert_eq!(collect_keys(&map).len(), 2);
+    }
+}
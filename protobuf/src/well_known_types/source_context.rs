@@ -25,6 +25,7 @@
 ///  protobuf element, like the file in which it is defined.
 // @@protoc_insertion_point(message:google.protobuf.SourceContext)
 #[derive(PartialEq,Clone,Default,Debug)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct SourceContext {
     // message fields
     ///  The path-qualified name of the .proto file that contained the associated
@@ -33,6 +34,7 @@ pub struct SourceContext {
     pub file_name: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.SourceContext.special_fields)
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub special_fields: crate::SpecialFields,
 }
 
@@ -47,6 +49,30 @@ impl SourceContext {
         ::std::default::Default::default()
     }
 
+    // file_name
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn clear_file_name(&mut self) {
+        self.file_name.clear();
+    }
+
+    pub fn set_file_name(&mut self, v: ::std::string::String) {
+        self.file_name = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_name(&mut self) -> &mut ::std::string::String {
+        &mut self.file_name
+    }
+
+    // Take field
+    pub fn take_file_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.file_name, ::std::string::String::new())
+    }
+
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
@@ -232,3 +258,38 @@ pub fn file_descriptor() -> &'static crate::reflect::FileDescriptor {
         crate::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::SourceContext;
+
+    #[test]
+    fn set_file_name() {
+        let mut m = SourceContext::new();
+        m.set_file_name("google/protobuf/source_context.proto".to_string());
+        assert_eq!("google/protobuf/source_context.proto", m.file_name());
+    }
+
+    #[test]
+    fn mut_file_name() {
+        let mut m = SourceContext::new();
+        m.mut_file_name().push_str("abc");
+        assert_eq!("abc", m.file_name());
+    }
+
+    #[test]
+    fn take_file_name() {
+        let mut m = SourceContext::new();
+        m.set_file_name("abc".to_string());
+        assert_eq!("abc", m.take_file_name());
+        assert_eq!("", m.file_name());
+    }
+
+    #[test]
+    fn clear_file_name() {
+        let mut m = SourceContext::new();
+        m.set_file_name("abc".to_string());
+        m.clear_file_name();
+        assert_eq!("", m.file_name());
+    }
+}